@@ -80,6 +80,8 @@ pub struct BankingStageStats {
     last_report: AtomicInterval,
     id: u32,
     receive_and_buffer_packets_count: AtomicUsize,
+    receive_messages_coalesced_count: AtomicUsize,
+    max_receive_message_packet_count: AtomicUsize,
     dropped_packets_count: AtomicUsize,
     pub(crate) dropped_duplicated_packets_count: AtomicUsize,
     dropped_forward_packets_count: AtomicUsize,
@@ -115,6 +117,12 @@ impl BankingStageStats {
         0 == self
             .receive_and_buffer_packets_count
             .load(Ordering::Relaxed) as u64
+            + self
+                .receive_messages_coalesced_count
+                .load(Ordering::Relaxed) as u64
+            + self
+                .max_receive_message_packet_count
+                .load(Ordering::Relaxed) as u64
             + self.dropped_packets_count.load(Ordering::Relaxed) as u64
             + self
                 .dropped_duplicated_packets_count
@@ -153,6 +161,18 @@ impl BankingStageStats {
                         .swap(0, Ordering::Relaxed) as i64,
                     i64
                 ),
+                (
+                    "receive_messages_coalesced_count",
+                    self.receive_messages_coalesced_count
+                        .swap(0, Ordering::Relaxed) as i64,
+                    i64
+                ),
+                (
+                    "max_receive_message_packet_count",
+                    self.max_receive_message_packet_count
+                        .swap(0, Ordering::Relaxed) as i64,
+                    i64
+                ),
                 (
                     "dropped_packets_count",
                     self.dropped_packets_count.swap(0, Ordering::Relaxed) as i64,
@@ -9,12 +9,16 @@ use {
         accounts::TransactionLoadResult,
         bank::{Bank, CommitTransactionCounts, TransactionBalancesSet},
         bank_utils,
+        nonce_info::{NonceFull, NonceInfo},
         prioritization_fee_cache::PrioritizationFeeCache,
         transaction_batch::TransactionBatch,
         transaction_results::{TransactionExecutionResult, TransactionResults},
         vote_sender_types::ReplayVoteSender,
     },
-    solana_sdk::{pubkey::Pubkey, saturating_add_assign},
+    solana_sdk::{
+        account::ReadableAccount, pubkey::Pubkey, saturating_add_assign,
+        transaction::SanitizedTransaction,
+    },
     solana_transaction_status::{
         token_balances::TransactionTokenBalancesSet, TransactionTokenBalance,
     },
@@ -72,6 +76,18 @@ impl Committer {
         executed_non_vote_transactions_count: usize,
         executed_with_successful_result_count: usize,
     ) -> (u64, Vec<CommitTransactionDetails>) {
+        let num_transactions = batch.sanitized_transactions().len();
+        debug_assert_eq!(
+            loaded_transactions.len(),
+            num_transactions,
+            "loaded transactions must match the number of batch transactions"
+        );
+        debug_assert_eq!(
+            execution_results.len(),
+            num_transactions,
+            "execution results must match the number of batch transactions"
+        );
+
         let (last_blockhash, lamports_per_signature) =
             bank.last_blockhash_and_lamports_per_signature();
 
@@ -99,16 +115,8 @@ impl Committer {
         ));
         execute_and_commit_timings.commit_us = commit_time_us;
 
-        let commit_transaction_statuses = tx_results
-            .execution_results
-            .iter()
-            .map(|execution_result| match execution_result.details() {
-                Some(details) => CommitTransactionDetails::Committed {
-                    compute_units: details.executed_units,
-                },
-                None => CommitTransactionDetails::NotCommitted,
-            })
-            .collect();
+        let commit_transaction_statuses =
+            Self::commit_transaction_details(&tx_results.execution_results);
 
         let ((), find_and_send_votes_us) = measure_us!({
             bank_utils::find_and_send_votes(
@@ -130,6 +138,116 @@ impl Committer {
         (commit_time_us, commit_transaction_statuses)
     }
 
+    /// Computes the `CommitTransactionDetails` that `commit_transactions`
+    /// would report for the given execution results, along with the native
+    /// balances before and after execution, without committing to the bank,
+    /// sending transaction statuses or votes, or updating the prioritization
+    /// fee cache. Post balances of successful transactions are read from the
+    /// executed accounts in `loaded_transactions`. Failed transactions only
+    /// pay the fee, or roll back to the nonce accounts if they use a durable
+    /// nonce; transactions that were not executed report their pre balances.
+    pub fn simulate_commit(
+        batch: &TransactionBatch,
+        execution_results: &[TransactionExecutionResult],
+        loaded_transactions: &[TransactionLoadResult],
+        bank: &Bank,
+    ) -> (Vec<CommitTransactionDetails>, TransactionBalancesSet) {
+        let num_transactions = batch.sanitized_transactions().len();
+        debug_assert_eq!(
+            loaded_transactions.len(),
+            num_transactions,
+            "loaded transactions must match the number of batch transactions"
+        );
+        debug_assert_eq!(
+            execution_results.len(),
+            num_transactions,
+            "execution results must match the number of batch transactions"
+        );
+
+        let pre_balances = bank.collect_balances(batch);
+        let post_balances = batch
+            .sanitized_transactions()
+            .iter()
+            .zip(execution_results)
+            .zip(loaded_transactions)
+            .zip(&pre_balances)
+            .map(
+                |(((tx, execution_result), (loaded_transaction, nonce)), pre_balances)| {
+                    match loaded_transaction {
+                        Ok(loaded_transaction) if execution_result.was_executed_successfully() => {
+                            loaded_transaction
+                                .accounts
+                                .iter()
+                                .take(tx.message().account_keys().len())
+                                .map(|(_pubkey, account)| account.lamports())
+                                .collect()
+                        }
+                        Ok(_) if execution_result.was_executed() => {
+                            Self::failed_transaction_post_balances(
+                                tx,
+                                pre_balances,
+                                nonce.as_ref(),
+                                bank,
+                            )
+                        }
+                        _ => pre_balances.clone(),
+                    }
+                },
+            )
+            .collect();
+
+        (
+            Self::commit_transaction_details(execution_results),
+            TransactionBalancesSet::new(pre_balances, post_balances),
+        )
+    }
+
+    /// Post balances of an executed transaction that failed. Mirrors
+    /// `Accounts::collect_accounts_to_store` and
+    /// `Bank::filter_program_errors_and_collect_fee`: without a durable nonce
+    /// only the fee is withdrawn from the fee payer, otherwise the nonce and
+    /// fee payer accounts are rolled back to their fee-paid state.
+    fn failed_transaction_post_balances(
+        tx: &SanitizedTransaction,
+        pre_balances: &[u64],
+        nonce: Option<&NonceFull>,
+        bank: &Bank,
+    ) -> Vec<u64> {
+        let mut post_balances = pre_balances.to_vec();
+        match nonce {
+            Some(nonce) => {
+                for (i, key) in tx.message().account_keys().iter().enumerate() {
+                    if key == nonce.address() {
+                        post_balances[i] = nonce.account().lamports();
+                    } else if i == 0 {
+                        if let Some(fee_payer_account) = nonce.fee_payer_account() {
+                            post_balances[i] = fee_payer_account.lamports();
+                        }
+                    }
+                }
+            }
+            None => {
+                let fee = bank.get_fee_for_message(tx.message()).unwrap_or_default();
+                post_balances[0] = post_balances[0].saturating_sub(fee);
+            }
+        }
+        post_balances
+    }
+
+    fn commit_transaction_details(
+        execution_results: &[TransactionExecutionResult],
+    ) -> Vec<CommitTransactionDetails> {
+        execution_results
+            .iter()
+            .map(|execution_result| match execution_result.details() {
+                Some(details) => CommitTransactionDetails::Committed {
+                    compute_units: details.executed_units,
+                },
+                None => CommitTransactionDetails::NotCommitted,
+            })
+            .collect()
+    }
+
     fn collect_balances_and_send_status_batch(
         &self,
         tx_results: TransactionResults,
@@ -175,3 +293,195 @@ impl Committer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crossbeam_channel::unbounded,
+        solana_ledger::genesis_utils::{create_genesis_config, GenesisConfigInfo},
+        solana_program_runtime::timings::ExecuteTimings,
+        solana_sdk::{
+            clock::MAX_PROCESSING_AGE, fee_calculator::FeeRateGovernor, signer::Signer,
+            system_instruction, system_transaction, transaction::Transaction,
+        },
+    };
+
+    fn create_bank_with_fees(mint_lamports: u64) -> (Arc<Bank>, GenesisConfigInfo) {
+        let mut genesis_config_info = create_genesis_config(mint_lamports);
+        genesis_config_info.genesis_config.fee_rate_governor = FeeRateGovernor::new(5_000, 0);
+        let bank = Arc::new(Bank::new_for_tests(&genesis_config_info.genesis_config));
+        (bank, genesis_config_info)
+    }
+
+    #[test]
+    fn test_simulate_commit() {
+        let (
+            bank,
+            GenesisConfigInfo {
+                genesis_config,
+                mint_keypair,
+                ..
+            },
+        ) = create_bank_with_fees(100_000);
+        let to = Pubkey::new_unique();
+        let transactions = vec![SanitizedTransaction::from_transaction_for_tests(
+            system_transaction::transfer(&mint_keypair, &to, 1, genesis_config.hash()),
+        )];
+        let batch = bank.prepare_sanitized_batch(&transactions);
+        let output = bank.load_and_execute_transactions(
+            &batch,
+            MAX_PROCESSING_AGE,
+            false,
+            false,
+            false,
+            &mut ExecuteTimings::default(),
+            None,
+            None,
+        );
+
+        let transaction_count = bank.transaction_count();
+        let (commit_transaction_details, balances) = Committer::simulate_commit(
+            &batch,
+            &output.execution_results,
+            &output.loaded_transactions,
+            &bank,
+        );
+
+        assert!(matches!(
+            commit_transaction_details[..],
+            [CommitTransactionDetails::Committed { compute_units }] if compute_units > 0
+        ));
+
+        // account keys of a transfer are [from, to, system_program]
+        let fee = bank.get_fee_for_message(transactions[0].message()).unwrap();
+        assert_eq!(fee, 5_000);
+        assert_eq!(balances.pre_balances.len(), 1);
+        assert_eq!(balances.post_balances.len(), 1);
+        let pre_balances = &balances.pre_balances[0];
+        let post_balances = &balances.post_balances[0];
+        assert_eq!(pre_balances[..2], [100_000, 0]);
+        assert_eq!(post_balances.len(), pre_balances.len());
+        assert_eq!(post_balances[..2], [100_000 - 1 - fee, 1]);
+
+        // the bank is not modified
+        assert_eq!(bank.get_balance(&mint_keypair.pubkey()), 100_000);
+        assert_eq!(bank.get_balance(&to), 0);
+        assert_eq!(bank.transaction_count(), transaction_count);
+    }
+
+    #[test]
+    fn test_simulate_commit_failed_transaction() {
+        let (
+            bank,
+            GenesisConfigInfo {
+                genesis_config,
+                mint_keypair,
+                ..
+            },
+        ) = create_bank_with_fees(100_000);
+        let to = Pubkey::new_unique();
+        // the first transfer succeeds, the second overdraws the payer
+        let transactions = vec![SanitizedTransaction::from_transaction_for_tests(
+            Transaction::new_signed_with_payer(
+                &[
+                    system_instruction::transfer(&mint_keypair.pubkey(), &to, 1),
+                    system_instruction::transfer(&mint_keypair.pubkey(), &to, 1_000_000),
+                ],
+                Some(&mint_keypair.pubkey()),
+                &[&mint_keypair],
+                genesis_config.hash(),
+            ),
+        )];
+        let batch = bank.prepare_sanitized_batch(&transactions);
+        let output = bank.load_and_execute_transactions(
+            &batch,
+            MAX_PROCESSING_AGE,
+            false,
+            false,
+            false,
+            &mut ExecuteTimings::default(),
+            None,
+            None,
+        );
+        assert!(output.execution_results[0].was_executed());
+        assert!(!output.execution_results[0].was_executed_successfully());
+
+        let (commit_transaction_details, balances) = Committer::simulate_commit(
+            &batch,
+            &output.execution_results,
+            &output.loaded_transactions,
+            &bank,
+        );
+
+        assert!(matches!(
+            commit_transaction_details[..],
+            [CommitTransactionDetails::Committed { .. }]
+        ));
+
+        // only the fee is withdrawn from the fee payer, the first transfer is
+        // not reflected in the post balances
+        let fee = bank.get_fee_for_message(transactions[0].message()).unwrap();
+        assert_eq!(fee, 5_000);
+        let pre_balances = &balances.pre_balances[0];
+        let post_balances = &balances.post_balances[0];
+        assert_eq!(pre_balances[..2], [100_000, 0]);
+        assert_eq!(post_balances[0], pre_balances[0] - fee);
+        assert_eq!(post_balances[1..], pre_balances[1..]);
+
+        // the bank is not modified
+        assert_eq!(bank.get_balance(&mint_keypair.pubkey()), 100_000);
+        assert_eq!(bank.get_balance(&to), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "execution results must match the number of batch transactions")]
+    fn test_commit_transactions_mismatched_lengths() {
+        let GenesisConfigInfo {
+            genesis_config,
+            mint_keypair,
+            ..
+        } = create_genesis_config(10_000);
+        let bank = Arc::new(Bank::new_for_tests(&genesis_config));
+        let transactions = vec![SanitizedTransaction::from_transaction_for_tests(
+            system_transaction::transfer(
+                &mint_keypair,
+                &Pubkey::new_unique(),
+                1,
+                genesis_config.hash(),
+            ),
+        )];
+        let batch = bank.prepare_sanitized_batch(&transactions);
+        let mut output = bank.load_and_execute_transactions(
+            &batch,
+            MAX_PROCESSING_AGE,
+            false,
+            false,
+            false,
+            &mut ExecuteTimings::default(),
+            None,
+            None,
+        );
+
+        let (replay_vote_sender, _replay_vote_receiver) = unbounded();
+        let committer = Committer::new(
+            None,
+            replay_vote_sender,
+            Arc::new(PrioritizationFeeCache::new(0u64)),
+        );
+        committer.commit_transactions(
+            &batch,
+            &mut output.loaded_transactions,
+            vec![], // execution_results
+            None,
+            &bank,
+            &mut PreBalanceInfo::default(),
+            &mut LeaderExecuteAndCommitTimings::default(),
+            output.signature_count,
+            output.executed_transactions_count,
+            output.executed_non_vote_transactions_count,
+            output.executed_with_successful_result_count,
+        );
+    }
+}
@@ -72,6 +72,18 @@ impl ImmutableDeserializedPacket {
         })
     }
 
+    /// Builds an immutable packet from an already-sanitized transaction by
+    /// serializing it into a new packet.
+    pub fn from_sanitized_transaction(
+        transaction: &SanitizedTransaction,
+    ) -> Result<Self, DeserializedPacketError> {
+        let mut packet = Packet::from_data(None, transaction.to_versioned_transaction())?;
+        packet
+            .meta_mut()
+            .set_simple_vote(transaction.is_simple_vote_transaction());
+        Self::new(packet)
+    }
+
     pub fn original_packet(&self) -> &Packet {
         &self.original_packet
     }
@@ -148,7 +160,12 @@ fn packet_message(packet: &Packet) -> Result<&[u8], DeserializedPacketError> {
 mod tests {
     use {
         super::*,
-        solana_sdk::{signature::Keypair, system_transaction},
+        solana_sdk::{
+            signature::Keypair,
+            system_transaction,
+            transaction::{MessageHash, SimpleAddressLoader},
+        },
+        solana_vote_program::vote_transaction::new_vote_transaction,
     };
 
     #[test]
@@ -164,4 +181,49 @@ mod tests {
 
         assert!(matches!(deserialized_packet, Ok(_)));
     }
+
+    #[test]
+    fn deserialized_packet_from_sanitized_transaction() {
+        let tx = SanitizedTransaction::from_transaction_for_tests(system_transaction::transfer(
+            &Keypair::new(),
+            &solana_sdk::pubkey::new_rand(),
+            1,
+            Hash::new_unique(),
+        ));
+        let deserialized_packet =
+            ImmutableDeserializedPacket::from_sanitized_transaction(&tx).unwrap();
+
+        assert_eq!(deserialized_packet.message_hash(), tx.message_hash());
+        assert!(!deserialized_packet.is_simple_vote());
+    }
+
+    #[test]
+    fn deserialized_packet_from_sanitized_vote_transaction() {
+        let node_keypair = Keypair::new();
+        let vote_keypair = Keypair::new();
+        let vote_tx = new_vote_transaction(
+            vec![0, 1, 2],
+            Hash::new_unique(),
+            Hash::new_unique(),
+            &node_keypair,
+            &vote_keypair,
+            &vote_keypair,
+            None,
+        );
+        let tx = SanitizedTransaction::try_create(
+            VersionedTransaction::from(vote_tx),
+            MessageHash::Compute,
+            None,
+            SimpleAddressLoader::Disabled,
+        )
+        .unwrap();
+        assert!(tx.is_simple_vote_transaction());
+
+        let deserialized_packet =
+            ImmutableDeserializedPacket::from_sanitized_transaction(&tx).unwrap();
+
+        assert_eq!(deserialized_packet.message_hash(), tx.message_hash());
+        assert!(deserialized_packet.is_simple_vote());
+        assert_eq!(deserialized_packet.priority(), 0);
+    }
 }
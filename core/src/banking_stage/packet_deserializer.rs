@@ -25,6 +25,11 @@ pub struct ReceivePacketResults {
     pub passed_sigverify_count: u64,
     /// Number of packets failing sigverify
     pub failed_sigverify_count: u64,
+    /// Number of messages (`BankingPacketBatch`es) received from sigverify stage and
+    /// coalesced into these results
+    pub num_messages_coalesced: usize,
+    /// Total number of packets, across all packet batches, in the largest received message
+    pub max_message_packet_count: usize,
 }
 
 pub struct PacketDeserializer {
@@ -51,7 +56,8 @@ impl PacketDeserializer {
         recv_timeout: Duration,
         capacity: usize,
     ) -> Result<ReceivePacketResults, RecvTimeoutError> {
-        let (packet_count, packet_batches) = self.receive_until(recv_timeout, capacity)?;
+        let (packet_count, max_message_packet_count, packet_batches) =
+            self.receive_until(recv_timeout, capacity)?;
 
         // Note: this can be removed after feature `round_compute_unit_price` is activated in
         // mainnet-beta
@@ -60,6 +66,7 @@ impl PacketDeserializer {
 
         Ok(Self::deserialize_and_collect_packets(
             packet_count,
+            max_message_packet_count,
             &packet_batches,
             round_compute_unit_price_enabled,
         ))
@@ -69,6 +76,7 @@ impl PacketDeserializer {
     /// them into ReceivePacketResults
     fn deserialize_and_collect_packets(
         packet_count: usize,
+        max_message_packet_count: usize,
         banking_batches: &[BankingPacketBatch],
         round_compute_unit_price_enabled: bool,
     ) -> ReceivePacketResults {
@@ -109,15 +117,19 @@ impl PacketDeserializer {
             new_tracer_stats_option: aggregated_tracer_packet_stats_option,
             passed_sigverify_count: passed_sigverify_count as u64,
             failed_sigverify_count: failed_sigverify_count as u64,
+            num_messages_coalesced: banking_batches.len(),
+            max_message_packet_count,
         }
     }
 
-    /// Receives packet batches from sigverify stage with a timeout
+    /// Receives packet batches from sigverify stage with a timeout. Returns the total number of
+    /// packets received, the total number of packets in the largest received message, and the
+    /// messages.
     fn receive_until(
         &self,
         recv_timeout: Duration,
         packet_count_upperbound: usize,
-    ) -> Result<(usize, Vec<BankingPacketBatch>), RecvTimeoutError> {
+    ) -> Result<(usize, usize, Vec<BankingPacketBatch>), RecvTimeoutError> {
         let start = Instant::now();
 
         let message = self.packet_batch_receiver.recv_timeout(recv_timeout)?;
@@ -126,15 +138,18 @@ impl PacketDeserializer {
             .iter()
             .map(|batch| batch.len())
             .sum::<usize>();
+        let mut max_message_packet_count = num_packets_received;
         let mut messages = vec![message];
 
         while let Ok(message) = self.packet_batch_receiver.try_recv() {
             let packet_batches = &message.0;
             trace!("got more packet batches in packet deserializer");
-            num_packets_received += packet_batches
+            let message_packet_count = packet_batches
                 .iter()
                 .map(|batch| batch.len())
                 .sum::<usize>();
+            num_packets_received += message_packet_count;
+            max_message_packet_count = max_message_packet_count.max(message_packet_count);
             messages.push(message);
 
             if start.elapsed() >= recv_timeout || num_packets_received >= packet_count_upperbound {
@@ -142,7 +157,7 @@ impl PacketDeserializer {
             }
         }
 
-        Ok((num_packets_received, messages))
+        Ok((num_packets_received, max_message_packet_count, messages))
     }
 
     fn generate_packet_indexes(packet_batch: &PacketBatch) -> Vec<usize> {
@@ -173,7 +188,10 @@ impl PacketDeserializer {
 mod tests {
     use {
         super::*,
+        crossbeam_channel::unbounded,
+        solana_ledger::genesis_utils::{create_genesis_config, GenesisConfigInfo},
         solana_perf::packet::to_packet_batches,
+        solana_runtime::bank::Bank,
         solana_sdk::{
             hash::Hash, pubkey::Pubkey, signature::Keypair, system_transaction,
             transaction::Transaction,
@@ -186,11 +204,13 @@ mod tests {
 
     #[test]
     fn test_deserialize_and_collect_packets_empty() {
-        let results = PacketDeserializer::deserialize_and_collect_packets(0, &[], false);
+        let results = PacketDeserializer::deserialize_and_collect_packets(0, 0, &[], false);
         assert_eq!(results.deserialized_packets.len(), 0);
         assert!(results.new_tracer_stats_option.is_none());
         assert_eq!(results.passed_sigverify_count, 0);
         assert_eq!(results.failed_sigverify_count, 0);
+        assert_eq!(results.num_messages_coalesced, 0);
+        assert_eq!(results.max_message_packet_count, 0);
     }
 
     #[test]
@@ -201,6 +221,7 @@ mod tests {
 
         let packet_count: usize = packet_batches.iter().map(|x| x.len()).sum();
         let results = PacketDeserializer::deserialize_and_collect_packets(
+            packet_count,
             packet_count,
             &[BankingPacketBatch::new((packet_batches, None))],
             false,
@@ -220,6 +241,7 @@ mod tests {
 
         let packet_count: usize = packet_batches.iter().map(|x| x.len()).sum();
         let results = PacketDeserializer::deserialize_and_collect_packets(
+            packet_count,
             packet_count,
             &[BankingPacketBatch::new((packet_batches, None))],
             false,
@@ -229,4 +251,31 @@ mod tests {
         assert_eq!(results.passed_sigverify_count, 1);
         assert_eq!(results.failed_sigverify_count, 1);
     }
+
+    #[test]
+    fn test_receive_packets_coalesced_messages() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(Bank::new_for_tests(
+            &genesis_config,
+        ))));
+        let (sender, receiver) = unbounded();
+        let packet_deserializer = PacketDeserializer::new(receiver, bank_forks);
+
+        let transactions = vec![random_transfer(), random_transfer(), random_transfer()];
+        for banking_batch in [
+            BankingPacketBatch::new((to_packet_batches(&transactions[..1], 1), None)),
+            BankingPacketBatch::new((to_packet_batches(&transactions, 1), None)),
+            BankingPacketBatch::new((to_packet_batches(&transactions[..2], 2), None)),
+        ] {
+            sender.send(banking_batch).unwrap();
+        }
+
+        let results = packet_deserializer
+            .receive_packets(Duration::from_millis(100), usize::MAX)
+            .unwrap();
+        assert_eq!(results.deserialized_packets.len(), 6);
+        assert_eq!(results.passed_sigverify_count, 6);
+        assert_eq!(results.num_messages_coalesced, 3);
+        assert_eq!(results.max_message_packet_count, 3);
+    }
 }
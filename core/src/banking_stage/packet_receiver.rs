@@ -96,6 +96,8 @@ impl PacketReceiver {
             new_tracer_stats_option,
             passed_sigverify_count,
             failed_sigverify_count,
+            num_messages_coalesced,
+            max_message_packet_count,
         }: ReceivePacketResults,
         unprocessed_transaction_storage: &mut UnprocessedTransactionStorage,
         banking_stage_stats: &mut BankingStageStats,
@@ -128,6 +130,12 @@ impl PacketReceiver {
         banking_stage_stats
             .receive_and_buffer_packets_count
             .fetch_add(packet_count, Ordering::Relaxed);
+        banking_stage_stats
+            .receive_messages_coalesced_count
+            .fetch_add(num_messages_coalesced, Ordering::Relaxed);
+        banking_stage_stats
+            .max_receive_message_packet_count
+            .fetch_max(max_message_packet_count, Ordering::Relaxed);
         banking_stage_stats
             .dropped_packets_count
             .fetch_add(dropped_packets_count, Ordering::Relaxed);
@@ -64,23 +64,26 @@ impl ThreadAwareAccountLocks {
     /// for the given thread, otherwise `None` is returned.
     /// `allowed_threads` is a set of threads that the caller restricts locking to.
     /// If accounts are schedulable, then they are locked for the thread
-    /// selected by the `thread_selector` function.
+    /// selected by the `thread_selector` function. If the `thread_selector`
+    /// does not select a thread, the accounts are treated as not schedulable.
     pub(crate) fn try_lock_accounts<'a>(
         &mut self,
         write_account_locks: impl Iterator<Item = &'a Pubkey> + Clone,
         read_account_locks: impl Iterator<Item = &'a Pubkey> + Clone,
         allowed_threads: ThreadSet,
-        thread_selector: impl FnOnce(ThreadSet) -> ThreadId,
+        thread_selector: impl FnOnce(ThreadSet) -> Option<ThreadId>,
     ) -> Option<ThreadId> {
         let schedulable_threads = self.accounts_schedulable_threads(
             write_account_locks.clone(),
             read_account_locks.clone(),
         )? & allowed_threads;
-        (!schedulable_threads.is_empty()).then(|| {
-            let thread_id = thread_selector(schedulable_threads);
-            self.lock_accounts(write_account_locks, read_account_locks, thread_id);
-            thread_id
-        })
+        if schedulable_threads.is_empty() {
+            return None;
+        }
+
+        let thread_id = thread_selector(schedulable_threads)?;
+        self.lock_accounts(write_account_locks, read_account_locks, thread_id);
+        Some(thread_id)
     }
 
     /// Unlocks the accounts for the given thread.
@@ -406,8 +409,8 @@ mod tests {
     const TEST_ANY_THREADS: ThreadSet = ThreadSet::any(TEST_NUM_THREADS);
 
     // Simple thread selector to select the first schedulable thread
-    fn test_thread_selector(thread_set: ThreadSet) -> ThreadId {
-        thread_set.contained_threads_iter().next().unwrap()
+    fn test_thread_selector(thread_set: ThreadSet) -> Option<ThreadId> {
+        thread_set.contained_threads_iter().next()
     }
 
     #[test]
@@ -493,6 +496,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_lock_accounts_no_thread_selected() {
+        let pk1 = Pubkey::new_unique();
+        let pk2 = Pubkey::new_unique();
+        let mut locks = ThreadAwareAccountLocks::new(TEST_NUM_THREADS);
+        assert_eq!(
+            locks.try_lock_accounts(
+                [&pk1].into_iter(),
+                [&pk2].into_iter(),
+                TEST_ANY_THREADS,
+                |_thread_set| None
+            ),
+            None
+        );
+        assert!(locks.write_locks.is_empty());
+        assert!(locks.read_locks.is_empty());
+    }
+
+    #[test]
+    fn test_try_lock_accounts_no_thread_selected_outstanding_locks() {
+        let pk1 = Pubkey::new_unique();
+        let pk2 = Pubkey::new_unique();
+        let mut locks = ThreadAwareAccountLocks::new(TEST_NUM_THREADS);
+        locks.write_lock_account(&pk1, 0);
+        locks.write_lock_account(&pk1, 0);
+        locks.read_lock_account(&pk2, 1);
+        locks.read_lock_account(&pk2, 2);
+
+        assert_eq!(
+            locks.try_lock_accounts(
+                [&pk1].into_iter(),
+                [&pk2].into_iter(),
+                TEST_ANY_THREADS,
+                |thread_set| {
+                    assert_eq!(thread_set, ThreadSet::only(0));
+                    None
+                }
+            ),
+            None
+        );
+
+        let write_locks = &locks.write_locks[&pk1];
+        assert_eq!(write_locks.thread_id, 0);
+        assert_eq!(write_locks.lock_count, 2);
+        let mut expected_read_thread_set = ThreadSet::only(1);
+        expected_read_thread_set.insert(2);
+        let read_locks = &locks.read_locks[&pk2];
+        assert_eq!(read_locks.thread_set, expected_read_thread_set);
+        assert_eq!(read_locks.lock_counts[..TEST_NUM_THREADS], [0, 1, 1, 0]);
+    }
+
     #[test]
     fn test_accounts_schedulable_threads_no_outstanding_locks() {
         let pk1 = Pubkey::new_unique();
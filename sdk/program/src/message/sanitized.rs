@@ -1,6 +1,6 @@
 use {
     crate::{
-        hash::Hash,
+        hash::{Hash, Hasher},
         instruction::CompiledInstruction,
         message::{
             legacy,
@@ -15,7 +15,7 @@ use {
         solana_program::{system_instruction::SystemInstruction, system_program},
         sysvar::instructions::{BorrowedAccountMeta, BorrowedInstruction},
     },
-    std::{borrow::Cow, convert::TryFrom},
+    std::{borrow::Cow, collections::HashSet, convert::TryFrom},
     thiserror::Error,
 };
 
@@ -174,6 +174,14 @@ impl SanitizedMessage {
         }
     }
 
+    /// Returns the total number of bytes of instruction data in this message.
+    pub fn total_instruction_data_len(&self) -> usize {
+        self.instructions()
+            .iter()
+            .map(|instruction| instruction.data.len())
+            .sum()
+    }
+
     /// Program instructions iterator which includes each instruction's program
     /// id.
     pub fn program_instructions_iter(
@@ -189,6 +197,27 @@ impl SanitizedMessage {
         })
     }
 
+    /// Returns the program id invoked by the given instruction, or `None` if
+    /// its program id index is not a valid account index for this message.
+    pub fn program_id_for_instruction(&self, instruction: &CompiledInstruction) -> Option<&Pubkey> {
+        self.account_keys()
+            .get(usize::from(instruction.program_id_index))
+    }
+
+    /// Returns an iterator over the program ids invoked by the top-level
+    /// instructions of this message, in instruction order. A program id is
+    /// yielded once per instruction that invokes it, so it may be repeated.
+    pub fn invoked_program_ids(&self) -> impl Iterator<Item = &Pubkey> {
+        self.program_instructions_iter()
+            .map(|(program_id, _instruction)| program_id)
+    }
+
+    /// Returns the deduplicated set of program ids invoked by the top-level
+    /// instructions of this message.
+    pub fn invoked_program_set(&self) -> HashSet<Pubkey> {
+        self.invoked_program_ids().copied().collect()
+    }
+
     /// Returns the list of account keys that are loaded for this message.
     pub fn account_keys(&self) -> AccountKeys {
         match self {
@@ -205,6 +234,39 @@ impl SanitizedMessage {
         }
     }
 
+    /// Returns a fingerprint of the message content: its header, account keys
+    /// (including any loaded addresses) and instructions. Signatures and the
+    /// recent blockhash are not included, so transactions that differ only in
+    /// those produce the same fingerprint.
+    pub fn content_fingerprint(&self) -> u64 {
+        let mut hasher = Hasher::default();
+        let header = self.header();
+        hasher.hash(&[
+            header.num_required_signatures,
+            header.num_readonly_signed_accounts,
+            header.num_readonly_unsigned_accounts,
+        ]);
+
+        let account_keys = self.account_keys();
+        hasher.hash(&(account_keys.len() as u64).to_le_bytes());
+        for key in account_keys.iter() {
+            hasher.hash(key.as_ref());
+        }
+
+        for instruction in self.instructions() {
+            hasher.hash(&[instruction.program_id_index]);
+            hasher.hash(&(instruction.accounts.len() as u64).to_le_bytes());
+            hasher.hash(&instruction.accounts);
+            hasher.hash(&(instruction.data.len() as u64).to_le_bytes());
+            hasher.hash(&instruction.data);
+        }
+
+        let hash_bytes = hasher.result().to_bytes();
+        let mut fingerprint_bytes = [0u8; 8];
+        fingerprint_bytes.copy_from_slice(&hash_bytes[..8]);
+        u64::from_le_bytes(fingerprint_bytes)
+    }
+
     /// Returns true if the account at the specified index is an input to some
     /// program instruction in this message.
     fn is_key_passed_to_program(&self, key_index: usize) -> bool {
@@ -247,6 +309,63 @@ impl SanitizedMessage {
         index < usize::from(self.header().num_required_signatures)
     }
 
+    /// Returns an iterator over the account keys that are writable by the
+    /// instructions in this message.
+    pub fn writable_account_keys(&self) -> impl Iterator<Item = &Pubkey> {
+        self.account_keys()
+            .iter()
+            .enumerate()
+            .filter(move |(index, _key)| self.is_writable(*index))
+            .map(|(_index, key)| key)
+    }
+
+    /// Returns an iterator over the account keys that are only readable by
+    /// the instructions in this message.
+    pub fn readonly_account_keys(&self) -> impl Iterator<Item = &Pubkey> {
+        self.account_keys()
+            .iter()
+            .enumerate()
+            .filter(move |(index, _key)| !self.is_writable(*index))
+            .map(|(_index, key)| key)
+    }
+
+    /// Returns the number of writable accounts in this message, not counting
+    /// the fee payer at index 0.
+    pub fn num_writable_non_payer_accounts(&self) -> usize {
+        (1..self.account_keys().len())
+            .filter(|index| self.is_writable(*index))
+            .count()
+    }
+
+    /// Returns an iterator over the writable accounts passed to top-level
+    /// instructions that invoke `program_id`. An account is yielded once per
+    /// occurrence, so it may be repeated.
+    pub fn writable_accounts_for_program<'a>(
+        &'a self,
+        program_id: &'a Pubkey,
+    ) -> impl Iterator<Item = &'a Pubkey> {
+        self.program_instructions_iter()
+            .filter(move |(instruction_program_id, _instruction)| {
+                *instruction_program_id == program_id
+            })
+            .flat_map(move |(_program_id, instruction)| {
+                instruction
+                    .accounts
+                    .iter()
+                    .map(|account_index| usize::from(*account_index))
+                    .filter(move |account_index| self.is_writable(*account_index))
+                    .filter_map(move |account_index| self.account_keys().get(account_index))
+            })
+    }
+
+    /// Returns an iterator over the account keys that signed this message, in
+    /// account index order. The first signer is always the fee payer.
+    pub fn signer_keys(&self) -> impl Iterator<Item = &Pubkey> {
+        self.account_keys()
+            .iter()
+            .take(usize::from(self.header().num_required_signatures))
+    }
+
     /// Return the resolved addresses for this message if it has any.
     fn loaded_lookup_table_addresses(&self) -> Option<&LoadedAddresses> {
         match &self {
@@ -255,6 +374,19 @@ impl SanitizedMessage {
         }
     }
 
+    /// Return the number of readonly accounts that are required signers of
+    /// this message.
+    pub fn num_readonly_signed_accounts(&self) -> u8 {
+        self.header().num_readonly_signed_accounts
+    }
+
+    /// Return the number of readonly accounts in the message's static account
+    /// keys that are not required signers. Readonly addresses loaded from
+    /// lookup tables are not included.
+    pub fn num_readonly_unsigned_accounts(&self) -> u8 {
+        self.header().num_readonly_unsigned_accounts
+    }
+
     /// Return the number of readonly accounts loaded by this message.
     pub fn num_readonly_accounts(&self) -> usize {
         let loaded_readonly_addresses = self
@@ -347,7 +479,7 @@ impl SanitizedMessage {
 
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::message::v0, std::collections::HashSet};
+    use {super::*, crate::message::v0};
 
     #[test]
     fn test_try_from_message() {
@@ -428,6 +560,54 @@ mod tests {
         assert_eq!(v0_message.num_readonly_accounts(), 3);
     }
 
+    #[test]
+    fn test_num_readonly_signed_and_unsigned_accounts() {
+        let legacy_message = SanitizedMessage::try_from(legacy::Message {
+            header: MessageHeader {
+                num_required_signatures: 3,
+                num_readonly_signed_accounts: 2,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            ..legacy::Message::default()
+        })
+        .unwrap();
+
+        assert_eq!(legacy_message.num_readonly_signed_accounts(), 2);
+        assert_eq!(legacy_message.num_readonly_unsigned_accounts(), 1);
+
+        // readonly addresses loaded from lookup tables are not counted
+        let v0_message = SanitizedMessage::V0(v0::LoadedMessage::new(
+            v0::Message {
+                header: MessageHeader {
+                    num_required_signatures: 3,
+                    num_readonly_signed_accounts: 2,
+                    num_readonly_unsigned_accounts: 1,
+                },
+                account_keys: vec![
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                ],
+                ..v0::Message::default()
+            },
+            LoadedAddresses {
+                writable: vec![Pubkey::new_unique()],
+                readonly: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            },
+        ));
+
+        assert_eq!(v0_message.num_readonly_signed_accounts(), 2);
+        assert_eq!(v0_message.num_readonly_unsigned_accounts(), 1);
+        assert_eq!(v0_message.num_readonly_accounts(), 5);
+    }
+
     #[test]
     fn test_get_ix_signers() {
         let signer0 = Pubkey::new_unique();
@@ -468,6 +648,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_signer_keys() {
+        let signer0 = Pubkey::new_unique();
+        let signer1 = Pubkey::new_unique();
+        let non_signer = Pubkey::new_unique();
+        let loader_key = Pubkey::new_unique();
+        let instructions = vec![CompiledInstruction::new(3, &(), vec![0, 1, 2])];
+
+        let message = SanitizedMessage::try_from(legacy::Message::new_with_compiled_instructions(
+            2,
+            1,
+            2,
+            vec![signer0, signer1, non_signer, loader_key],
+            Hash::default(),
+            instructions,
+        ))
+        .unwrap();
+
+        let signer_keys = message.signer_keys().collect::<Vec<_>>();
+        assert_eq!(signer_keys, vec![&signer0, &signer1]);
+        assert_eq!(signer_keys[0], message.fee_payer());
+        assert_eq!(
+            signer_keys.len(),
+            usize::from(message.header().num_required_signatures)
+        );
+    }
+
+    #[test]
+    fn test_program_id_for_instruction() {
+        let key0 = Pubkey::new_unique();
+        let key1 = Pubkey::new_unique();
+        let program_id0 = Pubkey::new_unique();
+        let program_id1 = Pubkey::new_unique();
+        let instructions = vec![
+            CompiledInstruction::new(2, &(), vec![0, 1]),
+            CompiledInstruction::new(3, &(), vec![0]),
+        ];
+
+        let message = SanitizedMessage::try_from(legacy::Message::new_with_compiled_instructions(
+            1,
+            0,
+            2,
+            vec![key0, key1, program_id0, program_id1],
+            Hash::default(),
+            instructions,
+        ))
+        .unwrap();
+
+        for (program_id, instruction) in message.program_instructions_iter() {
+            assert_eq!(
+                message.program_id_for_instruction(instruction),
+                Some(program_id)
+            );
+        }
+        assert_eq!(
+            message.program_id_for_instruction(&message.instructions()[1]),
+            Some(&program_id1)
+        );
+        assert_eq!(
+            message.program_id_for_instruction(&CompiledInstruction::new(4, &(), vec![])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_total_instruction_data_len() {
+        let key0 = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let instructions = vec![
+            CompiledInstruction::new_from_raw_parts(1, vec![0; 7], vec![0]),
+            CompiledInstruction::new_from_raw_parts(1, vec![], vec![]),
+            CompiledInstruction::new_from_raw_parts(1, vec![1; 32], vec![0]),
+        ];
+        let expected_len = instructions
+            .iter()
+            .map(|instruction| instruction.data.len())
+            .sum::<usize>();
+
+        let message = SanitizedMessage::try_from(legacy::Message::new_with_compiled_instructions(
+            1,
+            0,
+            1,
+            vec![key0, program_id],
+            Hash::default(),
+            instructions,
+        ))
+        .unwrap();
+
+        assert_eq!(message.total_instruction_data_len(), expected_len);
+        assert_eq!(message.total_instruction_data_len(), 39);
+    }
+
+    #[test]
+    fn test_num_writable_non_payer_accounts() {
+        let payer = Pubkey::new_unique();
+        let writable_signer = Pubkey::new_unique();
+        let writable_non_signer = Pubkey::new_unique();
+        let readonly_non_signer = Pubkey::new_unique();
+
+        let message = SanitizedMessage::try_from(legacy::Message {
+            header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![
+                payer,
+                writable_signer,
+                writable_non_signer,
+                readonly_non_signer,
+            ],
+            ..legacy::Message::default()
+        })
+        .unwrap();
+
+        assert!(message.is_writable(0));
+        assert_eq!(message.num_writable_non_payer_accounts(), 2);
+    }
+
+    #[test]
+    fn test_writable_accounts_for_program() {
+        let payer = Pubkey::new_unique();
+        let writable0 = Pubkey::new_unique();
+        let writable1 = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let program_id0 = Pubkey::new_unique();
+        let program_id1 = Pubkey::new_unique();
+        let instructions = vec![
+            CompiledInstruction::new(4, &(), vec![0, 1, 3]),
+            CompiledInstruction::new(5, &(), vec![2, 3]),
+        ];
+
+        let message = SanitizedMessage::try_from(legacy::Message::new_with_compiled_instructions(
+            1,
+            0,
+            3,
+            vec![
+                payer,
+                writable0,
+                writable1,
+                readonly,
+                program_id0,
+                program_id1,
+            ],
+            Hash::default(),
+            instructions,
+        ))
+        .unwrap();
+
+        assert_eq!(
+            message
+                .writable_accounts_for_program(&program_id0)
+                .collect::<Vec<_>>(),
+            vec![&payer, &writable0]
+        );
+        assert_eq!(
+            message
+                .writable_accounts_for_program(&program_id1)
+                .collect::<Vec<_>>(),
+            vec![&writable1]
+        );
+        assert_eq!(
+            message
+                .writable_accounts_for_program(&Pubkey::new_unique())
+                .count(),
+            0
+        );
+    }
+
     #[test]
     #[allow(clippy::get_first)]
     fn test_is_writable_account_cache() {
@@ -4,12 +4,14 @@ pub use crate::message::{AddressLoader, SimpleAddressLoader};
 use {
     super::SanitizedVersionedTransaction,
     crate::{
+        compute_budget,
         hash::Hash,
         message::{
             legacy,
             v0::{self, LoadedAddresses},
             LegacyMessage, SanitizedMessage, VersionedMessage,
         },
+        nonce::NONCED_TX_MARKER_IX_INDEX,
         precompiles::verify_if_precompile,
         pubkey::Pubkey,
         sanitize::Sanitize,
@@ -183,6 +185,18 @@ impl SanitizedTransaction {
         self.is_simple_vote_tx
     }
 
+    /// Returns true if this transaction uses a durable nonce and, apart from
+    /// compute budget instructions, does nothing but advance the nonce account.
+    pub fn is_nonce_only(&self) -> bool {
+        self.message.get_durable_nonce().is_some()
+            && self
+                .message
+                .program_instructions_iter()
+                .enumerate()
+                .filter(|(index, _)| *index != usize::from(NONCED_TX_MARKER_IX_INDEX))
+                .all(|(_index, (program_id, _instruction))| compute_budget::check_id(program_id))
+    }
+
     /// Convert this sanitized transaction into a versioned transaction for
     /// recording in the ledger.
     pub fn to_versioned_transaction(&self) -> VersionedTransaction {
@@ -302,8 +316,16 @@ impl SanitizedTransaction {
 mod tests {
     use {
         super::*,
-        crate::signer::{keypair::Keypair, Signer},
-        solana_program::vote::{self, state::Vote},
+        crate::{
+            compute_budget::ComputeBudgetInstruction,
+            signer::{keypair::Keypair, Signer},
+        },
+        solana_program::{
+            instruction::Instruction,
+            system_instruction, system_program,
+            vote::{self, state::Vote},
+        },
+        std::collections::HashSet,
     };
 
     #[test]
@@ -370,4 +392,119 @@ mod tests {
             assert!(vote_transaction.is_simple_vote_transaction());
         }
     }
+
+    #[test]
+    fn test_writable_and_readonly_account_keys() {
+        let from_keypair = Keypair::new();
+        let to = Pubkey::new_unique();
+        let transaction = SanitizedTransaction::from_transaction_for_tests(
+            crate::system_transaction::transfer(&from_keypair, &to, 1, Hash::default()),
+        );
+        let account_locks = transaction.get_account_locks_unchecked();
+
+        assert_eq!(
+            transaction
+                .message()
+                .writable_account_keys()
+                .collect::<Vec<_>>(),
+            account_locks.writable
+        );
+        assert_eq!(
+            transaction
+                .message()
+                .readonly_account_keys()
+                .collect::<Vec<_>>(),
+            account_locks.readonly
+        );
+    }
+
+    #[test]
+    fn test_invoked_program_ids() {
+        let payer = Keypair::new();
+        let transaction =
+            SanitizedTransaction::from_transaction_for_tests(Transaction::new_signed_with_payer(
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_price(1),
+                    system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1),
+                    system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1),
+                ],
+                Some(&payer.pubkey()),
+                &[&payer],
+                Hash::default(),
+            ));
+        let message = transaction.message();
+
+        assert_eq!(
+            message.invoked_program_ids().collect::<Vec<_>>(),
+            vec![
+                &compute_budget::id(),
+                &system_program::id(),
+                &system_program::id()
+            ]
+        );
+        assert_eq!(
+            message.invoked_program_set(),
+            HashSet::from([compute_budget::id(), system_program::id()])
+        );
+    }
+
+    #[test]
+    fn test_content_fingerprint() {
+        let from_keypair = Keypair::new();
+        let to = Pubkey::new_unique();
+        let transaction = SanitizedTransaction::from_transaction_for_tests(
+            crate::system_transaction::transfer(&from_keypair, &to, 1, Hash::new_unique()),
+        );
+        let same_content_transaction = SanitizedTransaction::from_transaction_for_tests(
+            crate::system_transaction::transfer(&from_keypair, &to, 1, Hash::new_unique()),
+        );
+        let different_content_transaction = SanitizedTransaction::from_transaction_for_tests(
+            crate::system_transaction::transfer(&from_keypair, &to, 2, Hash::new_unique()),
+        );
+
+        assert_ne!(
+            transaction.signature(),
+            same_content_transaction.signature()
+        );
+        assert_eq!(
+            transaction.message().content_fingerprint(),
+            same_content_transaction.message().content_fingerprint()
+        );
+        assert_ne!(
+            transaction.message().content_fingerprint(),
+            different_content_transaction
+                .message()
+                .content_fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_is_nonce_only() {
+        let payer = Keypair::new();
+        let nonce_pubkey = Pubkey::new_unique();
+        let create_transaction = |instructions: &[Instruction]| {
+            SanitizedTransaction::from_transaction_for_tests(Transaction::new_signed_with_payer(
+                instructions,
+                Some(&payer.pubkey()),
+                &[&payer],
+                Hash::default(),
+            ))
+        };
+        let advance_nonce_instruction =
+            system_instruction::advance_nonce_account(&nonce_pubkey, &payer.pubkey());
+        let transfer_instruction =
+            system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+
+        assert!(create_transaction(&[advance_nonce_instruction.clone()]).is_nonce_only());
+        assert!(create_transaction(&[
+            advance_nonce_instruction.clone(),
+            ComputeBudgetInstruction::set_compute_unit_price(1),
+        ])
+        .is_nonce_only());
+        assert!(
+            !create_transaction(&[advance_nonce_instruction, transfer_instruction.clone()])
+                .is_nonce_only()
+        );
+        assert!(!create_transaction(&[transfer_instruction]).is_nonce_only());
+    }
 }